@@ -2,7 +2,7 @@ use std::collections::HashMap;
 
 use log::*;
 use nom::branch::alt;
-use nom::bytes::complete::{take, take_while1};
+use nom::bytes::complete::{tag, take};
 use nom::character::complete::{char, crlf, space0, space1};
 use nom::combinator::{opt, verify};
 use nom::lib::std::str::from_utf8;
@@ -38,22 +38,6 @@ fn is_a_char(chr: u8) -> bool {
     chr >= 0x21 && chr <= 0x7e
 }
 
-/// Returns true if the byte slice is a *single* non ASCII non-control char
-///
-/// [`A-CHAR`](https://tools.ietf.org/html/rfc3977#section-9.8)
-fn is_a_char_bytes(b: &[u8]) -> bool {
-    if b.len() > 1 {
-        false
-    } else {
-        is_a_char(b[0])
-    }
-}
-
-/// Take an A-CHAR from the slice
-fn take_a_char(b: &[u8]) -> IResult<&[u8], &[u8]> {
-    verify(take_ascii_byte, is_a_char_bytes)(b)
-}
-
 /// Take a single non-ascii UTF-8 character from the slice
 ///
 /// nom 5 lacks combinators to distinguish between ASCII and UTF-8 so we have to implement this
@@ -70,13 +54,75 @@ fn take_utf8_non_ascii(b: &[u8]) -> IResult<&[u8], &[u8]> {
     ))(b)
 }
 
-/// Take a single `A-CHAR` or `UTF8-non-ascii` from the slice
-/// ```abnf
-/// P-CHAR     = A-CHAR / UTF8-non-ascii
-/// A-CHAR     = %x21-7E
-/// ```
-fn take_p_char(b: &[u8]) -> IResult<&[u8], &[u8]> {
-    alt((take_a_char, take_utf8_non_ascii))(b)
+/// Range table (low/high pairs) for the `A-NOTCOLON` class used by the SSE4.2 scanner.
+const A_NOTCOLON_RANGES: [u8; 16] = [0x21, 0x39, 0x3b, 0x7e, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+/// Range table (low/high pairs) for the `A-CHAR` class used by the SSE4.2 scanner.
+const A_CHAR_RANGES: [u8; 16] = [0x21, 0x7e, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+
+/// Count the leading bytes of `b` that are ASCII and satisfy `valid`.
+///
+/// This amortizes the per-byte validation over whole spans: on x86/x86_64 with
+/// SSE4.2 it scans 16 bytes at a time, falling back to a scalar run loop on other
+/// targets. Scanning stops at the first byte outside the class, which for these
+/// ASCII classes includes any byte `>= 0x80` — leaving the caller to hand that
+/// span to the per-codepoint UTF-8 path.
+fn ascii_run(b: &[u8], valid: fn(u8) -> bool, ranges: &[u8; 16], nranges: i32) -> usize {
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    {
+        if is_x86_feature_detected!("sse4.2") {
+            // Safety: guarded by runtime feature detection.
+            return unsafe { ascii_run_sse42(b, ranges, nranges) };
+        }
+    }
+    let _ = (ranges, nranges);
+    ascii_run_scalar(b, valid)
+}
+
+/// Scalar fallback: advance while `valid` holds (which, for our ASCII classes,
+/// is false for any byte `>= 0x80`).
+fn ascii_run_scalar(b: &[u8], valid: fn(u8) -> bool) -> usize {
+    b.iter().position(|&byte| !valid(byte)).unwrap_or(b.len())
+}
+
+/// SSE4.2 run scanner: returns the offset of the first byte of `b` that is *not*
+/// inside one of the `nranges` low/high ranges in `ranges`.
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[target_feature(enable = "sse4.2")]
+unsafe fn ascii_run_sse42(b: &[u8], ranges: &[u8; 16], nranges: i32) -> usize {
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86::*;
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64::*;
+
+    const MODE: i32 = _SIDD_UBYTE_OPS | _SIDD_CMP_RANGES | _SIDD_NEGATIVE_POLARITY | _SIDD_LEAST_SIGNIFICANT;
+    let range_vec = _mm_loadu_si128(ranges.as_ptr() as *const __m128i);
+    let range_len = nranges * 2;
+
+    let mut offset = 0;
+    while offset + 16 <= b.len() {
+        let chunk = _mm_loadu_si128(b.as_ptr().add(offset) as *const __m128i);
+        // First index in `chunk` that falls outside the allowed ranges.
+        let idx = _mm_cmpestri(range_vec, range_len, chunk, 16, MODE);
+        if idx != 16 {
+            return offset + idx as usize;
+        }
+        offset += 16;
+    }
+    // Scan the sub-16-byte tail with the scalar predicate-free range check.
+    while offset < b.len() {
+        let byte = b[offset];
+        if !byte_in_ranges(byte, ranges, nranges) {
+            break;
+        }
+        offset += 1;
+    }
+    offset
+}
+
+/// Whether `byte` lies inside one of the first `nranges` low/high ranges.
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+fn byte_in_ranges(byte: u8, ranges: &[u8; 16], nranges: i32) -> bool {
+    (0..nranges as usize).any(|i| byte >= ranges[i * 2] && byte <= ranges[i * 2 + 1])
 }
 
 /// Take the header-name from a slice
@@ -85,27 +131,66 @@ fn take_p_char(b: &[u8]) -> IResult<&[u8], &[u8]> {
 ///
 /// [header-name](https://tools.ietf.org/html/rfc3977#section-9.8)
 fn take_header_name(b: &[u8]) -> IResult<&[u8], &[u8]> {
-    take_while1(is_a_notcolon)(b)
+    let n = ascii_run(b, is_a_notcolon, &A_NOTCOLON_RANGES, 2);
+    if n == 0 {
+        Err(nom::Err::Error(nom::error::make_error(b, nom::error::ErrorKind::TakeWhile1)))
+    } else {
+        Ok((&b[n..], &b[..n]))
+    }
 }
 
 /// A token is one or more `P-CHAR` characters
 ///
+/// The ASCII `A-CHAR` runs are scanned a span at a time via [`ascii_run`]; only
+/// when an actual non-ASCII byte is reached do we fall back to the per-codepoint
+/// `UTF8-non-ascii` path. This preserves the exact `P-CHAR` semantics while
+/// avoiding byte-at-a-time nom state on the common all-ASCII case.
+///
 /// [token](https://tools.ietf.org/html/rfc3977#section-9.8)
 fn take_token(b: &[u8]) -> IResult<&[u8], &[u8]> {
-    let (rest, token_len) = fold_many1(take_p_char, 0, |mut acc, slice| {
-        acc += slice.len();
-        acc
-    })(b)?;
+    let mut pos = 0;
+    loop {
+        pos += ascii_run(&b[pos..], is_a_char, &A_CHAR_RANGES, 1);
+        if pos < b.len() && b[pos] >= 0x80 {
+            match take_utf8_non_ascii(&b[pos..]) {
+                Ok((_, cp)) => pos += cp.len(),
+                Err(_) => break,
+            }
+        } else {
+            break;
+        }
+    }
 
-    let token = &b[..token_len];
-    Ok((rest, token))
+    if pos == 0 {
+        Err(nom::Err::Error(nom::error::make_error(b, nom::error::ErrorKind::Many1)))
+    } else {
+        Ok((&b[pos..], &b[..pos]))
+    }
 }
 
-/// Take a single byte
+/// Configuration for the header parsers.
 ///
-/// This combinator simply returns a single byte if it is ASCII
-fn take_ascii_byte(b: &[u8]) -> IResult<&[u8], &[u8]> {
-    verify(take(1u8), |uint: &[u8]| uint.is_ascii())(b)
+/// The strict default is [RFC 3977](https://tools.ietf.org/html/rfc3977)-compliant
+/// and requires `CRLF` line endings everywhere. Enabling `permissive_eol` lets the
+/// parsers accept lone `\r` or `\n` terminators — as real-world feeds and some
+/// servers emit — instead of erroring out on malformed articles.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HeaderParseOptions {
+    /// Accept `\r\n`, lone `\r`, or lone `\n` wherever a `CRLF` is otherwise required.
+    pub permissive_eol: bool,
+}
+
+/// Take a line ending, honoring `opts.permissive_eol`.
+///
+/// In strict mode only `CRLF` is accepted; in permissive mode a lone `\r` or `\n`
+/// is accepted too. `CRLF` is always tried first so it is never mis-split into a
+/// lone `\r` followed by a stray `\n`.
+fn take_eol(b: &[u8], opts: HeaderParseOptions) -> IResult<&[u8], &[u8]> {
+    if opts.permissive_eol {
+        alt((crlf, tag("\n"), tag("\r")))(b)
+    } else {
+        crlf(b)
+    }
 }
 
 /// The content of an Article Header
@@ -123,12 +208,12 @@ fn take_ascii_byte(b: &[u8]) -> IResult<&[u8], &[u8]> {
 /// * All of the header RFCs I've come indicate there is no whitespace allowed between tokens and
 /// CLRF characters. Thankfully mail servers don't follow RFCs and violate this anyways so we
 /// do allow this *non-compliant* behavior to ease user suffering
-fn take_header_content(b: &[u8]) -> IResult<&[u8], &[u8]> {
+fn take_header_content(b: &[u8], opts: HeaderParseOptions) -> IResult<&[u8], &[u8]> {
     let (rest, (_ws, _token, _more_tokens, _trailing_ws)) = tuple((
         space0,
         take_token,
         many0(tuple((
-            opt(tuple((space0, crlf))), // Per RFC this *should* be opt(crlf), see non-compliant whitespace note
+            opt(tuple((space0, |i| take_eol(i, opts)))), // Per RFC this *should* be opt(crlf), see non-compliant whitespace note
             space1,
             take_token,
         ))),
@@ -144,37 +229,99 @@ fn take_header_content(b: &[u8]) -> IResult<&[u8], &[u8]> {
 /// header = header-name ":" SP [header-content] CRLF
 /// header-content = [WS] token *( [CRLF] WS token )
 /// ```
-fn take_header(b: &[u8]) -> IResult<&[u8], (&[u8], &[u8])> {
+fn take_header(b: &[u8], opts: HeaderParseOptions) -> IResult<&[u8], (&[u8], &[u8])> {
     // he
     let (rest, (header_name, _, _, header_content)) = terminated(
         tuple((
             take_header_name,
             char(':'),
             char(' '),
-            opt(take_header_content),
+            opt(|i| take_header_content(i, opts)),
         )),
-        crlf,
+        |i| take_eol(i, opts),
     )(b)?;
     Ok((rest, (header_name, header_content.unwrap_or_default())))
 }
 
+/// An insertion-ordered, case-insensitive map from header name to [`Header`].
+///
+/// Iterating yields headers in the exact order they appeared on the wire, which
+/// matters for re-serialization, signature verification, and debugging. Lookups
+/// are O(1) and case-insensitive per the RFCs (`xref` matches `Xref`) while the
+/// original casing is retained on each [`Header`] for round-tripping.
+#[derive(Debug, Default, Clone)]
+pub struct HeaderMap {
+    /// Headers in first-appearance (wire) order.
+    entries: Vec<Header>,
+    /// Lowercased name -> index into `entries`.
+    index: HashMap<String, usize>,
+}
+
+impl HeaderMap {
+    /// Create an empty map.
+    pub fn new() -> Self {
+        HeaderMap::default()
+    }
+
+    /// Record a `content` value for `name`, preserving wire order.
+    ///
+    /// A repeated header (matched case-insensitively) appends its value to the
+    /// existing [`Header`] in the order the duplicates appeared; a new name is
+    /// pushed to the end so iteration order matches the wire.
+    fn push_value(&mut self, name: String, content: String) {
+        let key = name.to_ascii_lowercase();
+        match self.index.get(&key) {
+            Some(&idx) => self.entries[idx].content.push(content),
+            None => {
+                self.index.insert(key, self.entries.len());
+                self.entries.push(Header { name, content: vec![content] });
+            }
+        }
+    }
+
+    /// Look up a header by name, case-insensitively.
+    pub fn get(&self, name: &str) -> Option<&Header> {
+        self.index.get(&name.to_ascii_lowercase()).map(|&i| &self.entries[i])
+    }
+
+    /// Whether a header with `name` (matched case-insensitively) is present.
+    pub fn contains_key(&self, name: &str) -> bool {
+        self.index.contains_key(&name.to_ascii_lowercase())
+    }
+
+    /// Iterate headers in wire order.
+    pub fn iter(&self) -> impl Iterator<Item = &Header> {
+        self.entries.iter()
+    }
+
+    /// The number of distinct headers.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether there are no headers.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
 pub(crate) fn take_headers(b: &[u8]) -> IResult<&[u8], Headers> {
+    take_headers_with(b, HeaderParseOptions::default())
+}
+
+pub(crate) fn take_headers_with(b: &[u8], opts: HeaderParseOptions) -> IResult<&[u8], Headers> {
     // n.b. assuming there are no parsing bugs (big if there), it should be sound to use
     // from_utf8_unchecked on header names since we already did utf8 checks while parsing.
 
     let fold_headers = fold_many1(
-        take_header,
-        (HashMap::new(), 0),
+        move |i| take_header(i, opts),
+        (HeaderMap::new(), 0),
         |(mut map, mut len), (name, content)| {
             let name = String::from_utf8_lossy(name).to_string();
             let content = String::from_utf8_lossy(content).to_string();
             trace!("Found header name `{}` -- `{}`", name, content);
 
-            let header = map.entry(name.clone()).or_insert(Header {
-                name,
-                content: vec![],
-            });
-            header.content.push(content);
+            map.push_value(name, content);
 
             len += 1;
 
@@ -182,13 +329,657 @@ pub(crate) fn take_headers(b: &[u8]) -> IResult<&[u8], Headers> {
         },
     );
 
-    let (rest, (inner, len)) = terminated(fold_headers, crlf)(b)?;
+    let (rest, (inner, len)) = terminated(fold_headers, |i| take_eol(i, opts))(b)?;
 
     let headers = Headers { inner, len };
 
     Ok((rest, headers))
 }
 
+/// Decode any [RFC 2047](https://tools.ietf.org/html/rfc2047) "encoded-words" in a header value.
+///
+/// Internationalized headers arrive on the wire as ASCII blobs of the form
+/// `=?charset?encoding?encoded-text?=`, where `encoding` is `B` (base64) or `Q`
+/// (quoted-printable with `_` standing in for space). Any token that is not a
+/// well-formed encoded-word is emitted verbatim, so running this over a header
+/// that contains no encoded-words is a no-op.
+///
+/// Per [section 6.2](https://tools.ietf.org/html/rfc2047#section-6.2), linear
+/// whitespace separating two adjacent encoded-words is not part of the displayed
+/// text and is removed, which lets a base64 run that was split across several
+/// encoded-words rejoin cleanly.
+pub(crate) fn decode_encoded_words(input: &str) -> String {
+    let mut out = String::with_capacity(input.len());
+    // Whitespace we are holding back until we know whether it sits between two
+    // adjacent encoded-words (in which case it is dropped).
+    let mut pending_ws = String::new();
+    let mut prev_was_encoded = false;
+
+    for token in split_keep_whitespace(input) {
+        match token {
+            Token::Whitespace(ws) => pending_ws.push_str(ws),
+            Token::Word(word) => {
+                match decode_one_encoded_word(word) {
+                    Some(decoded) => {
+                        if !prev_was_encoded {
+                            out.push_str(&pending_ws);
+                        }
+                        out.push_str(&decoded);
+                        prev_was_encoded = true;
+                    }
+                    None => {
+                        out.push_str(&pending_ws);
+                        out.push_str(word);
+                        prev_was_encoded = false;
+                    }
+                }
+                pending_ws.clear();
+            }
+        }
+    }
+    out.push_str(&pending_ws);
+    out
+}
+
+enum Token<'a> {
+    Whitespace(&'a str),
+    Word(&'a str),
+}
+
+/// Split on runs of whitespace while keeping the separators so adjacent
+/// encoded-words can have their separating whitespace elided.
+fn split_keep_whitespace(input: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut start = 0;
+    let mut in_ws = input.as_bytes().first().map_or(false, |b| b.is_ascii_whitespace());
+    for (i, b) in input.bytes().enumerate() {
+        let is_ws = b.is_ascii_whitespace();
+        if is_ws != in_ws {
+            let chunk = &input[start..i];
+            tokens.push(if in_ws { Token::Whitespace(chunk) } else { Token::Word(chunk) });
+            start = i;
+            in_ws = is_ws;
+        }
+    }
+    if start < input.len() {
+        let chunk = &input[start..];
+        tokens.push(if in_ws { Token::Whitespace(chunk) } else { Token::Word(chunk) });
+    }
+    tokens
+}
+
+/// Decode a single `=?charset?encoding?text?=` word, returning `None` if `word`
+/// is not a compliant encoded-word (too long, contains whitespace, or malformed).
+fn decode_one_encoded_word(word: &str) -> Option<String> {
+    // An encoded-word may not exceed 75 characters or contain raw whitespace.
+    if word.len() > 75 || word.bytes().any(|b| b.is_ascii_whitespace()) {
+        return None;
+    }
+    let inner = word.strip_prefix("=?")?.strip_suffix("?=")?;
+    let mut parts = inner.splitn(3, '?');
+    let charset = parts.next()?;
+    let encoding = parts.next()?;
+    let text = parts.next()?;
+    if charset.is_empty() {
+        return None;
+    }
+
+    let bytes = match encoding {
+        "B" | "b" => decode_base64(text)?,
+        "Q" | "q" => decode_quoted_printable(text)?,
+        _ => return None,
+    };
+
+    Some(transcode(charset, &bytes))
+}
+
+/// Transcode raw decoded bytes from `charset` into a Rust `String`.
+///
+/// UTF-8 and US-ASCII are taken as-is (lossily); ISO-8859-1 / Latin-1 is mapped
+/// code point for code point. Unknown charsets fall back to a lossy UTF-8 read.
+fn transcode(charset: &str, bytes: &[u8]) -> String {
+    match charset.to_ascii_uppercase().as_str() {
+        "ISO-8859-1" | "LATIN1" | "ISO_8859-1" => bytes.iter().map(|&b| b as char).collect(),
+        _ => String::from_utf8_lossy(bytes).into_owned(),
+    }
+}
+
+/// Decode the `Q` (quoted-printable) encoding of RFC 2047: `_` is a space and
+/// `=XX` is a hex-escaped byte.
+fn decode_quoted_printable(text: &str) -> Option<Vec<u8>> {
+    let bytes = text.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'_' => {
+                out.push(b' ');
+                i += 1;
+            }
+            b'=' => {
+                let hi = hex_val(*bytes.get(i + 1)?)?;
+                let lo = hex_val(*bytes.get(i + 2)?)?;
+                out.push(hi << 4 | lo);
+                i += 3;
+            }
+            other => {
+                out.push(other);
+                i += 1;
+            }
+        }
+    }
+    Some(out)
+}
+
+fn hex_val(b: u8) -> Option<u8> {
+    match b {
+        b'0'..=b'9' => Some(b - b'0'),
+        b'a'..=b'f' => Some(b - b'a' + 10),
+        b'A'..=b'F' => Some(b - b'A' + 10),
+        _ => None,
+    }
+}
+
+/// Decode the standard base64 alphabet used by the `B` encoding.
+fn decode_base64(text: &str) -> Option<Vec<u8>> {
+    let mut out = Vec::with_capacity(text.len() / 4 * 3);
+    let mut acc = 0u32;
+    let mut bits = 0u32;
+    for b in text.bytes() {
+        if b == b'=' {
+            break;
+        }
+        let val = base64_val(b)?;
+        acc = acc << 6 | val as u32;
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            out.push((acc >> bits) as u8);
+        }
+    }
+    Some(out)
+}
+
+fn base64_val(b: u8) -> Option<u8> {
+    match b {
+        b'A'..=b'Z' => Some(b - b'A'),
+        b'a'..=b'z' => Some(b - b'a' + 26),
+        b'0'..=b'9' => Some(b - b'0' + 52),
+        b'+' => Some(62),
+        b'/' => Some(63),
+        _ => None,
+    }
+}
+
+/// A parsed mailbox, i.e. an optional display name plus an `addr-spec`.
+///
+/// [RFC 5322 section 3.4](https://tools.ietf.org/html/rfc5322#section-3.4)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mailbox {
+    /// The human-readable display name, if one was present (`Display Name <a@b>`).
+    pub display_name: Option<String>,
+    /// The local part of the address (left of the `@`).
+    pub local_part: String,
+    /// The domain of the address (right of the `@`).
+    pub domain: String,
+}
+
+/// A parsed RFC 5322 `date-time`, normalized to a fixed offset from UTC.
+///
+/// [RFC 5322 section 3.3](https://tools.ietf.org/html/rfc5322#section-3.3)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DateTime {
+    /// Day of the month, 1-31.
+    pub day: u8,
+    /// Month of the year, 1-12.
+    pub month: u8,
+    /// Four-digit year.
+    pub year: i32,
+    /// Hour, 0-23.
+    pub hour: u8,
+    /// Minute, 0-59.
+    pub minute: u8,
+    /// Second, 0-60 (a leap second is permitted).
+    pub second: u8,
+    /// Offset from UTC in seconds (e.g. `-0700` -> `-25200`).
+    pub offset_seconds: i32,
+}
+
+/// Skip comment/folding whitespace: runs of whitespace and nested `(...)` comments.
+///
+/// [CFWS](https://tools.ietf.org/html/rfc5322#section-3.2.2)
+fn cfws(input: &str) -> IResult<&str, ()> {
+    let mut rest = input;
+    loop {
+        let trimmed = rest.trim_start();
+        if let Some(after) = trimmed.strip_prefix('(') {
+            // Consume a (possibly nested) comment.
+            let mut depth = 1usize;
+            let mut idx = 0;
+            for (i, c) in after.char_indices() {
+                match c {
+                    '(' => depth += 1,
+                    ')' => {
+                        depth -= 1;
+                        if depth == 0 {
+                            idx = i + 1;
+                            break;
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            if depth != 0 {
+                // Unterminated comment; give up and leave the rest untouched.
+                return Ok((trimmed, ()));
+            }
+            rest = &after[idx..];
+        } else {
+            return Ok((trimmed, ()));
+        }
+    }
+}
+
+/// Parse a single `mailbox`: either `Display Name <addr-spec>` or a bare `addr-spec`.
+fn take_mailbox(input: &str) -> IResult<&str, Mailbox> {
+    let (input, _) = cfws(input)?;
+    if let Ok((rest, mailbox)) = take_angle_addr(input) {
+        Ok((rest, mailbox))
+    } else {
+        let (rest, (local_part, domain)) = take_addr_spec(input)?;
+        Ok((
+            rest,
+            Mailbox { display_name: None, local_part, domain },
+        ))
+    }
+}
+
+/// `[display-name] "<" addr-spec ">"`
+fn take_angle_addr(input: &str) -> IResult<&str, Mailbox> {
+    let open = input.find('<');
+    let (name_part, after_open) = match open {
+        Some(idx) => (input[..idx].trim(), &input[idx + 1..]),
+        None => return Err(nom::Err::Error(nom::error::make_error(input, nom::error::ErrorKind::Char))),
+    };
+    let close = after_open
+        .find('>')
+        .ok_or_else(|| nom::Err::Error(nom::error::make_error(after_open, nom::error::ErrorKind::Char)))?;
+    let addr = &after_open[..close];
+    let rest = &after_open[close + 1..];
+    let (_, (local_part, domain)) = take_addr_spec(addr.trim())?;
+    let display_name = if name_part.is_empty() {
+        None
+    } else {
+        Some(unquote_display_name(name_part))
+    };
+    Ok((rest, Mailbox { display_name, local_part, domain }))
+}
+
+/// Strip surrounding double-quotes from a quoted display name, if present.
+fn unquote_display_name(name: &str) -> String {
+    if name.len() >= 2 && name.starts_with('"') && name.ends_with('"') {
+        name[1..name.len() - 1].replace("\\\"", "\"")
+    } else {
+        name.to_string()
+    }
+}
+
+/// `local-part "@" domain`, stopping at whitespace, `>`, or a list comma.
+fn take_addr_spec(input: &str) -> IResult<&str, (String, String)> {
+    let end = input
+        .find(|c: char| c.is_whitespace() || c == '>' || c == ',')
+        .unwrap_or(input.len());
+    let addr = &input[..end];
+    let at = addr.find('@').ok_or_else(|| {
+        nom::Err::Error(nom::error::make_error(input, nom::error::ErrorKind::Char))
+    })?;
+    let local_part = addr[..at].to_string();
+    let domain = addr[at + 1..].to_string();
+    if local_part.is_empty() || domain.is_empty() {
+        return Err(nom::Err::Error(nom::error::make_error(input, nom::error::ErrorKind::Char)));
+    }
+    Ok((&input[end..], (local_part, domain)))
+}
+
+/// Map a month abbreviation to its 1-based number.
+fn month_number(name: &str) -> Option<u8> {
+    const MONTHS: [&str; 12] = [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ];
+    MONTHS.iter().position(|m| m.eq_ignore_ascii_case(name)).map(|i| i as u8 + 1)
+}
+
+/// Map a named or numeric zone to an offset from UTC in seconds.
+///
+/// Named obsolete zones follow [RFC 5322 section 4.3](https://tools.ietf.org/html/rfc5322#section-4.3);
+/// anything unrecognized is treated as `-0000` (UTC).
+fn zone_offset(zone: &str) -> i32 {
+    let bytes = zone.as_bytes();
+    if (bytes[0] == b'+' || bytes[0] == b'-') && zone.len() == 5 {
+        if let (Ok(h), Ok(m)) = (zone[1..3].parse::<i32>(), zone[3..5].parse::<i32>()) {
+            let magnitude = h * 3600 + m * 60;
+            return if bytes[0] == b'-' { -magnitude } else { magnitude };
+        }
+    }
+    match zone.to_ascii_uppercase().as_str() {
+        "UT" | "GMT" | "Z" => 0,
+        "EDT" => -4 * 3600,
+        "EST" | "CDT" => -5 * 3600,
+        "CST" | "MDT" => -6 * 3600,
+        "MST" | "PDT" => -7 * 3600,
+        "PST" => -8 * 3600,
+        _ => 0,
+    }
+}
+
+/// Parse an RFC 5322 `date-time`:
+///
+/// ```abnf
+/// date-time = [ day-of-week "," ] day month year hour ":" minute [ ":" second ] zone
+/// ```
+fn take_date_time(input: &str) -> IResult<&str, DateTime> {
+    let (input, _) = cfws(input)?;
+    // Drop an optional leading "day-of-week,".
+    let input = match input.split_once(',') {
+        Some((head, tail)) if head.trim().len() == 3 && head.trim().chars().all(|c| c.is_ascii_alphabetic()) => {
+            tail
+        }
+        _ => input,
+    };
+    let mut fields = input.split_whitespace();
+    let day = fields.next().and_then(|s| s.parse::<u8>().ok()).ok_or_else(mailbox_err(input))?;
+    let month = fields.next().and_then(month_number).ok_or_else(mailbox_err(input))?;
+    let year = fields.next().and_then(|s| s.parse::<i32>().ok()).map(normalize_year).ok_or_else(mailbox_err(input))?;
+    let time = fields.next().ok_or_else(mailbox_err(input))?;
+    let mut time_parts = time.split(':');
+    let hour = time_parts.next().and_then(|s| s.parse::<u8>().ok()).ok_or_else(mailbox_err(input))?;
+    let minute = time_parts.next().and_then(|s| s.parse::<u8>().ok()).ok_or_else(mailbox_err(input))?;
+    let second = time_parts.next().and_then(|s| s.parse::<u8>().ok()).unwrap_or(0);
+    let offset_seconds = fields.next().map(zone_offset).unwrap_or(0);
+    Ok((
+        "",
+        DateTime { day, month, year, hour, minute, second, offset_seconds },
+    ))
+}
+
+/// Expand a two-digit year per RFC 5322 section 4.3.
+fn normalize_year(year: i32) -> i32 {
+    match year {
+        0..=49 => 2000 + year,
+        50..=99 => 1900 + year,
+        _ => year,
+    }
+}
+
+fn mailbox_err(input: &str) -> impl Fn() -> nom::Err<nom::error::Error<&str>> {
+    move || nom::Err::Error(nom::error::make_error(input, nom::error::ErrorKind::Verify))
+}
+
+/// The result of feeding a buffer to a [`HeadersParser`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    /// The full header block has been seen; the payload is the number of bytes of
+    /// the stream the header block occupied (including the terminating blank line).
+    Complete(usize),
+    /// More bytes are required before the header block can be parsed.
+    Partial,
+}
+
+/// Errors produced by the incremental [`HeadersParser`].
+#[derive(Debug)]
+pub enum HeadersParseError {
+    /// The accumulated bytes did not parse as a valid header block.
+    Malformed,
+}
+
+/// A push-style parser for reading a header block off a partial stream.
+///
+/// Unlike [`take_headers`], which needs the whole block up front, `HeadersParser`
+/// can be fed one chunk at a time off a `BufRead`/async socket. Each
+/// [`parse`](HeadersParser::parse) call returns [`Status::Partial`] until the
+/// terminating blank line arrives, at which point it returns [`Status::Complete`]
+/// and the parsed headers are available via [`into_headers`](HeadersParser::into_headers).
+///
+/// The search for line boundaries does not revisit already-accepted headers across
+/// calls; only the logical header currently in flight is rescanned when more bytes
+/// arrive. Once the terminating blank line is found, the whole accumulated block
+/// is parsed in a single pass with [`take_headers`], from its first byte.
+#[derive(Debug, Default)]
+pub struct HeadersParser {
+    buf: Vec<u8>,
+    /// End of the last fully-accepted logical header (a header plus any folded
+    /// continuation lines). Scanning resumes here on the next call.
+    committed: usize,
+    headers: Option<Headers>,
+}
+
+impl HeadersParser {
+    /// Create an empty parser.
+    pub fn new() -> Self {
+        HeadersParser::default()
+    }
+
+    /// Feed `buf` to the parser, returning whether the header block is complete.
+    pub fn parse(&mut self, buf: &[u8]) -> Result<Status, HeadersParseError> {
+        self.buf.extend_from_slice(buf);
+        let mut pos = self.committed;
+        loop {
+            let line_end = match find_crlf(&self.buf[pos..]) {
+                Some(i) => pos + i,
+                // No terminating CRLF for the current line yet.
+                None => return Ok(Status::Partial),
+            };
+
+            if line_end == pos {
+                // A blank line terminates the header block.
+                let consumed = line_end + 2;
+                let (_, headers) = take_headers(&self.buf[..consumed])
+                    .map_err(|_| HeadersParseError::Malformed)?;
+                self.headers = Some(headers);
+                return Ok(Status::Complete(consumed));
+            }
+
+            let next = line_end + 2;
+            if next >= self.buf.len() {
+                // We can't yet tell whether the next line folds into this header.
+                return Ok(Status::Partial);
+            }
+
+            match self.buf[next] {
+                // A leading space/tab means the header is folded onto the next line.
+                b' ' | b'\t' => pos = next,
+                // Otherwise this logical header is complete; commit past it.
+                _ => {
+                    self.committed = next;
+                    pos = next;
+                }
+            }
+        }
+    }
+
+    /// A reference to the parsed headers once [`parse`](HeadersParser::parse) has
+    /// returned [`Status::Complete`].
+    pub fn headers(&self) -> Option<&Headers> {
+        self.headers.as_ref()
+    }
+
+    /// Consume the parser, yielding the parsed headers if the block was completed.
+    pub fn into_headers(self) -> Option<Headers> {
+        self.headers
+    }
+}
+
+/// Find the byte offset of the next `CRLF` in `b`, if any.
+fn find_crlf(b: &[u8]) -> Option<usize> {
+    b.windows(2).position(|w| w == b"\r\n")
+}
+
+/// Options controlling how [`Headers::generate_with`] renders headers back to the wire.
+#[derive(Debug, Clone)]
+pub struct GenerateOptions {
+    /// The maximum length of a rendered line before it is folded at whitespace.
+    ///
+    /// `None` disables folding entirely. The default (78) matches the RFC 5322
+    /// recommended line length and stays well inside the 998-octet hard limit.
+    /// Folding only happens at whitespace: a run of text with no whitespace that
+    /// is longer than the limit is emitted unbroken, so the limit is not a hard
+    /// cap, and over-long input is never rejected.
+    pub max_line_length: Option<usize>,
+    /// When set, content containing non-ASCII bytes is re-encoded as a single
+    /// [RFC 2047](https://tools.ietf.org/html/rfc2047) base64 encoded-word.
+    pub encode_non_ascii: bool,
+}
+
+impl Default for GenerateOptions {
+    fn default() -> Self {
+        GenerateOptions { max_line_length: Some(78), encode_non_ascii: false }
+    }
+}
+
+impl Header {
+    /// Render this header's values as `name: content CRLF` lines into `out`,
+    /// folding and optionally re-encoding per `opts`.
+    pub fn write(&self, out: &mut String, opts: &GenerateOptions) {
+        for content in &self.content {
+            let content = if opts.encode_non_ascii && !content.is_ascii() {
+                encode_encoded_word(content)
+            } else {
+                content.clone()
+            };
+            let line = format!("{}: {}", self.name, content);
+            fold_line(out, &line, opts.max_line_length);
+            out.push_str("\r\n");
+        }
+    }
+}
+
+/// Encode a string as a single RFC 2047 base64 UTF-8 encoded-word.
+fn encode_encoded_word(content: &str) -> String {
+    format!("=?UTF-8?B?{}?=", encode_base64(content.as_bytes()))
+}
+
+/// Fold `line` into `out` at whitespace so that emitted lines stay within `max`.
+///
+/// Where no whitespace falls within the budget, the line is broken at the next
+/// whitespace after it, or left unbroken if there is none, so such a line does
+/// exceed `max`.
+///
+/// A continuation starts with `CRLF` followed by a single leading space, as
+/// required for [folding](https://tools.ietf.org/html/rfc5322#section-3.2.2).
+fn fold_line(out: &mut String, line: &str, max: Option<usize>) {
+    let max = match max {
+        Some(m) if m > 0 => m,
+        _ => {
+            out.push_str(line);
+            return;
+        }
+    };
+
+    let mut remaining = line;
+    let mut first = true;
+    loop {
+        let indent = if first { 0 } else { 1 };
+        if remaining.len() + indent <= max {
+            if !first {
+                out.push_str("\r\n ");
+            }
+            out.push_str(remaining);
+            return;
+        }
+        // Find the last whitespace at or before the budget so the fold stays compliant.
+        let budget = max.saturating_sub(indent);
+        let split = remaining[..budget.min(remaining.len())]
+            .rfind(char::is_whitespace)
+            .or_else(|| remaining.find(char::is_whitespace));
+        match split {
+            Some(idx) if idx > 0 => {
+                if !first {
+                    out.push_str("\r\n ");
+                }
+                out.push_str(remaining[..idx].trim_end());
+                remaining = remaining[idx..].trim_start();
+                first = false;
+            }
+            _ => {
+                // No breakable whitespace left; emit the remainder as-is.
+                if !first {
+                    out.push_str("\r\n ");
+                }
+                out.push_str(remaining);
+                return;
+            }
+        }
+    }
+}
+
+/// Encode bytes using the standard base64 alphabet with `=` padding.
+fn encode_base64(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut out = String::with_capacity((bytes.len() + 2) / 3 * 4);
+    for chunk in bytes.chunks(3) {
+        let b0 = chunk[0] as u32;
+        let b1 = *chunk.get(1).unwrap_or(&0) as u32;
+        let b2 = *chunk.get(2).unwrap_or(&0) as u32;
+        let n = b0 << 16 | b1 << 8 | b2;
+        out.push(ALPHABET[(n >> 18 & 0x3f) as usize] as char);
+        out.push(ALPHABET[(n >> 12 & 0x3f) as usize] as char);
+        out.push(if chunk.len() > 1 { ALPHABET[(n >> 6 & 0x3f) as usize] as char } else { '=' });
+        out.push(if chunk.len() > 2 { ALPHABET[(n & 0x3f) as usize] as char } else { '=' });
+    }
+    out
+}
+
+impl Headers {
+    /// Render every header back to wire format using the default [`GenerateOptions`].
+    ///
+    /// The output is the header block only; it does *not* include the terminating
+    /// blank line that separates headers from the article body.
+    pub fn generate(&self) -> String {
+        self.generate_with(&GenerateOptions::default())
+    }
+
+    /// Render every header back to wire format using the supplied options.
+    pub fn generate_with(&self, opts: &GenerateOptions) -> String {
+        let mut out = String::new();
+        for header in self.inner.iter() {
+            header.write(&mut out, opts);
+        }
+        out
+    }
+
+    /// The parsed `From` mailbox, if present and well-formed.
+    pub fn from_address(&self) -> Option<Mailbox> {
+        let content = self.get("From")?.content.first()?;
+        take_mailbox(content).ok().map(|(_, mb)| mb)
+    }
+
+    /// The parsed `Date` header as a normalized [`DateTime`], if present and well-formed.
+    pub fn date(&self) -> Option<DateTime> {
+        let content = self.get("Date")?.content.first()?;
+        take_date_time(content).ok().map(|(_, dt)| dt)
+    }
+
+    /// The comma-separated group names of the `Newsgroups` header.
+    pub fn newsgroups(&self) -> Vec<&str> {
+        match self.get("Newsgroups").and_then(|h| h.content.first()) {
+            Some(content) => content.split(',').map(|g| g.trim()).filter(|g| !g.is_empty()).collect(),
+            None => Vec::new(),
+        }
+    }
+}
+
+impl Header {
+    /// The header content with any [RFC 2047](https://tools.ietf.org/html/rfc2047)
+    /// encoded-words decoded, so internationalized `Subject`/`From` values render
+    /// as real text instead of raw `=?...?=` blobs.
+    ///
+    /// This is an opt-in accessor; [`Header::content`] still holds the undecoded
+    /// value as parsed off the wire.
+    pub fn decoded_content(&self) -> Vec<String> {
+        self.content.iter().map(|c| decode_encoded_words(c)).collect()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -245,18 +1036,6 @@ mod tests {
         assert_eq!(rest, " some words 🐒 ".as_bytes())
     }
 
-    mod take_ascii_byte {
-        use super::*;
-        #[test]
-        fn happy_path() {
-            let (_rest, _char) = take_ascii_byte(b"5").unwrap();
-        }
-        #[test]
-        fn fail_on_unicode() {
-            assert!(take_ascii_byte("🤘 ".as_bytes()).is_err());
-        }
-    }
-
     #[test]
     fn test_take_header_name() {
         let (rest, header_name) = take_header_name(FOLDED_HEADER).unwrap();
@@ -270,7 +1049,8 @@ mod tests {
             b"by 2002:ac8:2aed:: with SMTP id c42mr5587158qta.202.1591290821135;\r\n        \
             Thu, 05 Jun 2020 10:13:41 -0700 (PDT)\r\n";
 
-        let (_rest, parsed_header) = take_header_content(&content[..]).unwrap();
+        let (_rest, parsed_header) =
+            take_header_content(&content[..], HeaderParseOptions::default()).unwrap();
 
         // header-content does include the final CRLF, that's part of the header
         assert_eq!(&content[..content.len() - 2], parsed_header)
@@ -285,7 +1065,8 @@ mod tests {
                 b"by 2002:ac8:2aed:: with SMTP id c42mr5587158qta.202.1591290821135;\r\n        \
             Thu, 05 Jun 2020 10:13:41 -0700 (PDT)\r\n";
 
-            let (rest, (header_name, parsed_content)) = take_header(FOLDED_HEADER).unwrap();
+            let (rest, (header_name, parsed_content)) =
+                take_header(FOLDED_HEADER, HeaderParseOptions::default()).unwrap();
             dbg!(from_utf8(&header_name).unwrap());
             dbg!(from_utf8(&rest).unwrap());
             assert_eq!(rest.len(), 0);
@@ -297,7 +1078,8 @@ mod tests {
         fn test_simple() {
             let header = "Xref: number.nntp.giganews.com mozilla.dev.platform:47661\r\n";
 
-            let (rest, (name, content)) = take_header(header.as_bytes()).unwrap();
+            let (rest, (name, content)) =
+                take_header(header.as_bytes(), HeaderParseOptions::default()).unwrap();
 
             assert_eq!(rest.len(), 0);
             assert_eq!(name, header.split(':').next().unwrap().as_bytes());
@@ -310,16 +1092,35 @@ mod tests {
         #[test]
         fn test_empty_contents() {
             let header = b"X-Spam-Level: \r\n";
-            let (_rest, (name, content)) = take_header(header).unwrap();
+            let (_rest, (name, content)) =
+                take_header(header, HeaderParseOptions::default()).unwrap();
             assert_eq!(name, b"X-Spam-Level");
             assert_eq!(content, b"");
         }
 
+        #[test]
+        fn test_strict_rejects_lone_lf() {
+            // A lone `\n` terminator must fail in the default strict mode.
+            let header = b"Subject: hi\n";
+            assert!(take_header(header, HeaderParseOptions::default()).is_err());
+        }
+
+        #[test]
+        fn test_permissive_accepts_lone_lf() {
+            let opts = HeaderParseOptions { permissive_eol: true };
+            let header = b"Subject: hi\n";
+            let (rest, (name, content)) = take_header(header, opts).unwrap();
+            assert_eq!(rest.len(), 0);
+            assert_eq!(name, b"Subject");
+            assert_eq!(content, b"hi");
+        }
+
         #[test]
         fn test_non_compliant_whitespace() {
             let header = b"X-Received: by 2002:a65:508c:: with SMTP id r12mr626047pgp.233.1591751885013; \r\n Tue, 09 Jun 2020 18:18:05 -0700 (PDT)\r\n";
 
-            let (_rest, (name, content)) = take_header(header).unwrap();
+            let (_rest, (name, content)) =
+                take_header(header, HeaderParseOptions::default()).unwrap();
             assert_eq!(name, b"X-Received");
             assert_eq!(
                 content,
@@ -327,6 +1128,191 @@ mod tests {
         }
     }
 
+    mod header_map {
+        use super::*;
+
+        #[test]
+        fn preserves_wire_order() {
+            let mut map = HeaderMap::new();
+            map.push_value("Subject".to_string(), "a".to_string());
+            map.push_value("From".to_string(), "b".to_string());
+            map.push_value("Newsgroups".to_string(), "c".to_string());
+            let names: Vec<&str> = map.iter().map(|h| h.name.as_str()).collect();
+            assert_eq!(names, vec!["Subject", "From", "Newsgroups"]);
+        }
+
+        #[test]
+        fn case_insensitive_lookup_keeps_original_casing() {
+            let mut map = HeaderMap::new();
+            map.push_value("Xref".to_string(), "server group:1".to_string());
+            assert!(map.contains_key("xref"));
+            let header = map.get("XREF").unwrap();
+            // lookup is case-insensitive but the original casing is retained
+            assert_eq!(header.name, "Xref");
+        }
+
+        #[test]
+        fn duplicates_append_in_order() {
+            let mut map = HeaderMap::new();
+            map.push_value("X-Received".to_string(), "first".to_string());
+            map.push_value("x-received".to_string(), "second".to_string());
+            assert_eq!(map.len(), 1);
+            assert_eq!(map.get("X-Received").unwrap().content, vec!["first", "second"]);
+        }
+    }
+
+    mod headers_parser {
+        use super::*;
+
+        const BLOCK: &[u8] = b"Subject: hello\r\nX-Received: by 2002;\r\n more folded\r\nFrom: a@b.com\r\n\r\n";
+
+        #[test]
+        fn completes_when_whole_block_fed() {
+            let mut parser = HeadersParser::new();
+            let status = parser.parse(BLOCK).unwrap();
+            assert_eq!(status, Status::Complete(BLOCK.len()));
+            let headers = parser.into_headers().unwrap();
+            assert!(headers.inner.contains_key("Subject"));
+            assert!(headers.inner.contains_key("X-Received"));
+        }
+
+        #[test]
+        fn resumes_across_partial_feeds() {
+            let mut parser = HeadersParser::new();
+            // split in the middle of the folded header
+            let (head, tail) = BLOCK.split_at(30);
+            assert_eq!(parser.parse(head).unwrap(), Status::Partial);
+            assert_eq!(parser.parse(tail).unwrap(), Status::Complete(BLOCK.len()));
+            assert!(parser.headers().unwrap().inner.contains_key("From"));
+        }
+
+        #[test]
+        fn partial_when_terminator_missing() {
+            let mut parser = HeadersParser::new();
+            assert_eq!(parser.parse(b"Subject: hi\r\n").unwrap(), Status::Partial);
+        }
+    }
+
+    mod generate {
+        use super::*;
+
+        #[test]
+        fn simple_header_round_trips() {
+            let header = Header { name: "Subject".to_string(), content: vec!["hello world".to_string()] };
+            let mut out = String::new();
+            header.write(&mut out, &GenerateOptions::default());
+            assert_eq!(out, "Subject: hello world\r\n");
+        }
+
+        #[test]
+        fn long_line_is_folded_at_whitespace() {
+            let content = "the quick brown fox jumped over the lazy dog and kept on running past the fence";
+            let header = Header { name: "X-Long".to_string(), content: vec![content.to_string()] };
+            let mut out = String::new();
+            header.write(&mut out, &GenerateOptions { max_line_length: Some(40), encode_non_ascii: false });
+            for line in out.trim_end_matches("\r\n").split("\r\n") {
+                assert!(line.len() <= 40, "line too long: {:?}", line);
+            }
+            assert!(out.contains("\r\n "));
+        }
+
+        #[test]
+        fn no_folding_when_disabled() {
+            let header = Header { name: "X".to_string(), content: vec!["a ".repeat(100)] };
+            let mut out = String::new();
+            header.write(&mut out, &GenerateOptions { max_line_length: None, encode_non_ascii: false });
+            assert_eq!(out.matches("\r\n").count(), 1);
+        }
+
+        #[test]
+        fn non_ascii_re_encoded() {
+            let header = Header { name: "Subject".to_string(), content: vec!["✓".to_string()] };
+            let mut out = String::new();
+            header.write(&mut out, &GenerateOptions { max_line_length: None, encode_non_ascii: true });
+            assert_eq!(out, "Subject: =?UTF-8?B?4pyT?=\r\n");
+            assert_eq!(decode_encoded_words("=?UTF-8?B?4pyT?="), "✓");
+        }
+    }
+
+    mod structured_headers {
+        use super::*;
+
+        #[test]
+        fn mailbox_with_display_name() {
+            let (_, mb) = take_mailbox("Jane Doe <jane@example.com>").unwrap();
+            assert_eq!(mb.display_name.as_deref(), Some("Jane Doe"));
+            assert_eq!(mb.local_part, "jane");
+            assert_eq!(mb.domain, "example.com");
+        }
+
+        #[test]
+        fn bare_addr_spec() {
+            let (_, mb) = take_mailbox("jane@example.com").unwrap();
+            assert_eq!(mb.display_name, None);
+            assert_eq!(mb.local_part, "jane");
+            assert_eq!(mb.domain, "example.com");
+        }
+
+        #[test]
+        fn mailbox_tolerates_comments() {
+            let (_, mb) = take_mailbox("(a comment) Jane <jane@example.com>").unwrap();
+            assert_eq!(mb.display_name.as_deref(), Some("Jane"));
+            assert_eq!(mb.local_part, "jane");
+        }
+
+        #[test]
+        fn date_with_day_of_week_and_offset() {
+            let (_, dt) = take_date_time("Fri, 05 Jun 2020 10:13:41 -0700").unwrap();
+            assert_eq!(dt.day, 5);
+            assert_eq!(dt.month, 6);
+            assert_eq!(dt.year, 2020);
+            assert_eq!(dt.hour, 10);
+            assert_eq!(dt.minute, 13);
+            assert_eq!(dt.second, 41);
+            assert_eq!(dt.offset_seconds, -7 * 3600);
+        }
+
+        #[test]
+        fn date_named_zone_and_no_seconds() {
+            let (_, dt) = take_date_time("21 Nov 1997 09:55 GMT").unwrap();
+            assert_eq!(dt.second, 0);
+            assert_eq!(dt.offset_seconds, 0);
+        }
+    }
+
+    mod decode_encoded_words {
+        use super::*;
+
+        #[test]
+        fn base64() {
+            assert_eq!(decode_encoded_words("=?UTF-8?B?4pyT?="), "✓");
+        }
+
+        #[test]
+        fn quoted_printable() {
+            assert_eq!(decode_encoded_words("=?ISO-8859-1?Q?Keld_J=F8rn_Simonsen?="), "Keld Jørn Simonsen");
+        }
+
+        #[test]
+        fn adjacent_words_drop_whitespace() {
+            // two base64 runs split across encoded-words rejoin with no space between
+            let input = "=?UTF-8?B?4pyT?=\r\n =?UTF-8?B?4pyT?=";
+            assert_eq!(decode_encoded_words(input), "✓✓");
+        }
+
+        #[test]
+        fn mixed_plain_and_encoded() {
+            let input = "Hello =?UTF-8?B?4pyT?= world";
+            assert_eq!(decode_encoded_words(input), "Hello ✓ world");
+        }
+
+        #[test]
+        fn non_encoded_emitted_verbatim() {
+            assert_eq!(decode_encoded_words("=?not an encoded word?="), "=?not an encoded word?=");
+            assert_eq!(decode_encoded_words("plain Subject"), "plain Subject");
+        }
+    }
+
     #[test]
     fn test_take_headers() {
         // strip the initial response line
@@ -340,6 +1326,16 @@ mod tests {
         assert_eq!(headers.get("X-Received").unwrap().content.len(), 2);
     }
 
+    #[test]
+    fn test_take_headers_permissive_lone_lf() {
+        let opts = HeaderParseOptions { permissive_eol: true };
+        let article = b"Subject: hi\nFrom: a@b.com\n\n";
+        let (rest, headers) = take_headers_with(article, opts).unwrap();
+        assert_eq!(rest.len(), 0);
+        assert!(headers.inner.contains_key("Subject"));
+        assert!(headers.inner.contains_key("From"));
+    }
+
     #[test]
     fn test_take_headers_with_trailing_whitespace() {
         let article = TEXT_ARTICLE_TRAILING_WHITESPACE.splitn(2, '\n').nth(1).unwrap();